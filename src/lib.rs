@@ -1,4 +1,5 @@
-//! This crate contains a single function `moveslice`. Its purpose 
+#![no_std]
+//! This crate contains the `Moveslice` trait. Its main purpose
 //! is to move a chunk within a slice around. It only uses safe functions,
 //! and acts efficiently by using the 
 //! [`split_at_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.split_at_mut)
@@ -10,38 +11,169 @@
 //! # Examples:
 //! 
 //! ```
-//! use moveslice::moveslice;
-//! 
+//! use moveslice::Moveslice;
+//!
 //! let mut arr = [1,2,3,4,5,6,7,8,9];
-//! 
+//!
 //! // The following moves the slice 3..6 to index 1.
 //! // In effect, it moves [4,5,6] over to where [2] is.
-//! moveslice(&mut arr, (3,6), 1);
+//! arr.moveslice(3..6, 1);
 //! assert_eq!(arr, [1,4,5,6,2,3,7,8,9]);
-//! 
+//!
 //! // The following moves the slice 3..6 to index 6.
 //! // In effect, it moves [6,2,3] over to where [7] is.
-//! moveslice(&mut arr, (3,6), 6);
+//! arr.moveslice(3..6, 6);
 //! assert_eq!(arr, [1,4,5,7,8,9,6,2,3]);
-//! 
-//! // The following attempts to move the slice beyond boundaries.
-//! // The index given is 7, which exists in the array, but the 
-//! // last element of the chunk will not fit (7 + 3 = 10 > 9).
-//! // Therefore, the following should fail.
-//! # #[should_panic]
-//! # fn main() {
-//! # let mut arr = [1,2,3,4,5,6,7,8,9];
-//! let result = moveslice(&mut arr, (3,6), 7);
-//! # }
-//! 
+//!
 //! // You could pass the destination as the same value as chunk.0.
 //! // However this would mean nothing is moved.
 //! // This doesn't panic, but it's a no-op.
-//! moveslice(&mut arr, (0,3), 0);
+//! arr.moveslice(0..3, 0);
+//! ```
+//!
+//! The following attempts to move the slice beyond boundaries.
+//! The index given is 7, which exists in the array, but the
+//! last element of the chunk will not fit (7 + 3 = 10 > 9).
+//! Therefore, the following panics:
+//!
+//! ```should_panic
+//! use moveslice::Moveslice;
+//!
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! arr.moveslice(3..6, 7); // will panic
+//! ```
+//!
+//! # Copying instead of moving
+//!
+//! `copyslice`/`try_copyslice` overwrite the destination range with the
+//! chunk's contents, leaving the rest of the slice untouched. Overlapping
+//! source and destination are handled without a scratch buffer.
+//!
+//! ```
+//! use moveslice::Moveslice;
+//!
+//! // Overlapping copy forwards (destination > chunk start) copies back-to-front.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! arr.copyslice(3..6, 4);
+//! assert_eq!(arr, [1,2,3,4,4,5,6,8,9]);
+//!
+//! // Overlapping copy backwards (destination < chunk start) copies front-to-front.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! arr.copyslice(3..6, 1);
+//! assert_eq!(arr, [1,4,5,6,5,6,7,8,9]);
+//!
+//! // Equal start is a no-op.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! arr.copyslice(0..3, 0);
+//! assert_eq!(arr, [1,2,3,4,5,6,7,8,9]);
+//!
+//! // A destination that runs past the slice is rejected.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! assert!(arr.try_copyslice(3..6, 7).is_err());
+//! ```
+//!
+//! # Swapping two regions
+//!
+//! `swap_ranges` exchanges two disjoint blocks of possibly different lengths
+//! in O(n) time without allocating. The elements between them keep their order
+//! and end up between the swapped blocks.
+//!
+//! ```
+//! use moveslice::Moveslice;
+//!
+//! // Swap a 2-element header with a 5-element body, leaving a non-empty gap.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! arr.swap_ranges(0..2, 4..9).unwrap();
+//! assert_eq!(arr, [5,6,7,8,9,3,4,1,2]);
+//!
+//! // Ranges that are out of order or overlap are rejected.
+//! let mut arr = [1,2,3,4,5,6,7,8,9];
+//! assert!(arr.swap_ranges(4..6, 0..2).is_err());
+//! assert!(arr.swap_ranges(0..4, 2..6).is_err());
 //! ```
 
-use std::ops::Bound::*;
-use std::ops::RangeBounds;
+use core::error::Error;
+use core::fmt;
+use core::ops::Bound::*;
+use core::ops::{Range, RangeBounds};
+
+/// The errors that the fallible `moveslice` operations can report.
+///
+/// The panicking variants format this same type for their message, so the two
+/// paths describe a failure identically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MovesliceError {
+    /// The range was given without a start bound.
+    ///
+    /// Reserved: `resolve_range` maps an unbounded start to `0`, so no current
+    /// path constructs this, but it is kept for callers that match exhaustively.
+    MissingStartBound,
+    /// The range was given without an end bound.
+    ///
+    /// Reserved: `resolve_range` maps an unbounded end to `len`, so no current
+    /// path constructs this, but it is kept for callers that match exhaustively.
+    MissingEndBound,
+    /// The destination would push the chunk past the end of the slice.
+    OutOfBounds { dest_start: usize, dest_end: usize, len: usize },
+    /// The resolved range was inverted or reached past the slice.
+    InvalidRange { start: usize, end: usize },
+    /// Two ranges that were required to be disjoint (with `a` before `b`)
+    /// overlapped or were out of order.
+    RangesOverlap { a: Range<usize>, b: Range<usize> },
+    /// An inclusive end or excluded start of `usize::MAX` overflowed while
+    /// being resolved.
+    BoundOverflow,
+}
+
+impl fmt::Display for MovesliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MovesliceError::MissingStartBound =>
+                write!(f, "A startbound is required."),
+            MovesliceError::MissingEndBound =>
+                write!(f, "An endbound, excluded or included, is required."),
+            MovesliceError::OutOfBounds { dest_start, dest_end, len } =>
+                write!(f, "Direction goes beyond slice [len = {}, destination = {}..{}]. ",
+                    len, dest_start, dest_end),
+            MovesliceError::InvalidRange { start, end } =>
+                write!(f, "Invalid range [start = {}, end = {}]. ", start, end),
+            MovesliceError::RangesOverlap { a, b } =>
+                write!(f, "Ranges must be disjoint and ordered [a = {}..{}, b = {}..{}]. ",
+                    a.start, a.end, b.start, b.end),
+            MovesliceError::BoundOverflow =>
+                write!(f, "A bound overflowed usize while being resolved."),
+        }
+    }
+}
+
+impl Error for MovesliceError {}
+
+/// Resolves an arbitrary [`RangeBounds`] into a concrete `start..end` pair,
+/// validated against `len`.
+///
+/// An unbounded start becomes `0` and an unbounded end becomes `len`. An
+/// inclusive end and an excluded start are shifted by one with a checked add,
+/// so an inclusive `usize::MAX` end reports an overflow instead of wrapping to
+/// `0`. The
+/// resulting range is guaranteed to satisfy `start <= end <= len`.
+fn resolve_range<R: RangeBounds<usize>>(bounds: R, len: usize) -> Result<Range<usize>, MovesliceError> {
+    let start = match bounds.start_bound() {
+        Included(&s) => s,
+        Excluded(&s) => s.checked_add(1).ok_or(MovesliceError::BoundOverflow)?,
+        Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Included(&e) => e.checked_add(1).ok_or(MovesliceError::BoundOverflow)?,
+        Excluded(&e) => e,
+        Unbounded => len,
+    };
+
+    if start > end || end > len {
+        return Err(MovesliceError::InvalidRange { start, end });
+    }
+
+    Ok(start..end)
+}
 
 /// Moves a slice around in an array.
 /// Works by splitting and rotating.
@@ -65,14 +197,10 @@ use std::ops::RangeBounds;
 /// showing what would be the placement of the chunk, and the length of the slice.
 /// 
 /// ```should_panic
-/// # use moveslice::moveslice;
-/// # fn main() {
+/// use moveslice::Moveslice;
 /// let mut arr = [1,2,3,4,5,6,7,8,9];
-/// let result = moveslice(&mut arr, (3,6), 7); // will panic
-/// # }
+/// arr.moveslice(3..6, 7); // will panic
 /// ```
-
-
 pub trait Moveslice<T, R> {
   type Target; 
   type Err; 
@@ -80,107 +208,123 @@ pub trait Moveslice<T, R> {
     where R: RangeBounds<usize>;
   fn try_moveslice(&mut self, bounds: R, destination: Self::Target) -> Result<(), Self::Err>
     where R: RangeBounds<usize>;
+  fn copyslice(&mut self, bounds: R, destination: Self::Target)
+    where T: Copy, R: RangeBounds<usize>;
+  fn try_copyslice(&mut self, bounds: R, destination: Self::Target) -> Result<(), Self::Err>
+    where T: Copy, R: RangeBounds<usize>;
+  fn swap_ranges(&mut self, a: R, b: R) -> Result<(), Self::Err>
+    where R: RangeBounds<usize>;
 }
 
 impl<T: 'static,R,A> Moveslice<T,R> for A where A: AsMut<[T]> {
     type Target = usize;
-    type Err = String;
+    type Err = MovesliceError;
 
     fn moveslice(&mut self, bounds: R, destination: Self::Target)
-    where R: RangeBounds<usize> 
+    where R: RangeBounds<usize>
+    {
+        self.try_moveslice(bounds, destination)
+            .unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    fn try_moveslice(&mut self, bounds: R, destination: Self::Target) -> Result<(), Self::Err>
+    where R: RangeBounds<usize>
     {
         let slice = self.as_mut();
-        let startbound = bounds.start_bound();
-        let endbound = bounds.end_bound();
-        let chunk = if let Included(x) = startbound {
-            if let Excluded(y) = endbound {
-                (*x,*y)
-            }
-            else if let Included(y) = endbound {
-                (*x,y+1)
-            }
-            else{
-                panic!("An endbound, excluded or included, is required.");
-            }
-        }
-        else {
-            panic!("A startbound is required.");
-        };
+        let len = slice.len();
+        let chunk = resolve_range(bounds, len)?;
 
-        if destination > chunk.0 {
-            let chunksize = chunk.1 - chunk.0;
-            let index1 = chunk.0;
+        if destination > chunk.start {
+            let chunksize = chunk.end - chunk.start;
+            let index1 = chunk.start;
             let index2 = destination + chunksize - index1;
 
             let (_, mid) = slice.split_at_mut(index1);
 
-            let mid = if destination + chunksize <= mid.len() {
+            let mid = if destination + chunksize <= len {
                 mid.split_at_mut(index2).0
             } else {
-                panic!("Direction goes beyond slice [len = {}, destination = {}..{}]. ",
-                        mid.len(), destination, destination + chunksize);
+                return Err(MovesliceError::OutOfBounds {
+                    dest_start: destination,
+                    dest_end: destination + chunksize,
+                    len,
+                });
             };
 
-            mid.rotate_left(chunk.1-chunk.0);
-        } else if destination < chunk.0 {
+            mid.rotate_left(chunksize);
+        } else if destination < chunk.start {
             let index1 = destination;
-            let index2 = chunk.1 - destination;
+            let index2 = chunk.end - destination;
 
             let (_, mid) = slice.split_at_mut(index1);
 
             let mid = mid.split_at_mut(index2).0;
 
-            mid.rotate_right(chunk.1-chunk.0);
+            mid.rotate_right(chunk.end - chunk.start);
         }
+
+        Ok(())
+}
+
+    fn copyslice(&mut self, bounds: R, destination: Self::Target)
+    where T: Copy, R: RangeBounds<usize>
+    {
+        self.try_copyslice(bounds, destination)
+            .unwrap_or_else(|e| panic!("{}", e));
     }
 
-    fn try_moveslice(&mut self, bounds: R, destination: Self::Target) -> Result<(), Self::Err> 
-    where R: RangeBounds<usize> 
+    fn try_copyslice(&mut self, bounds: R, destination: Self::Target) -> Result<(), Self::Err>
+    where T: Copy, R: RangeBounds<usize>
     {
         let slice = self.as_mut();
-        let startbound = bounds.start_bound();
-        let endbound = bounds.end_bound();
-        let chunk = if let Included(x) = startbound {
-            if let Excluded(y) = endbound {
-                (*x,*y)
-            }
-            else if let Included(y) = endbound {
-                (*x,y+1)
-            }
-            else{
-                return Err(String::from("An endbound, excluded or included, is required."));
-            }
-        }
-        else {
-            return Err(String::from("A startbound is required."));
-        };
-
-        if destination > chunk.0 {
-            let chunksize = chunk.1 - chunk.0;
-            let index1 = chunk.0;
-            let index2 = destination + chunksize - index1;
+        let chunk = resolve_range(bounds, slice.len())?;
 
-            let (_, mid) = slice.split_at_mut(index1);
+        let chunksize = chunk.end - chunk.start;
 
-            let mid = if destination + chunksize <= mid.len() {
-                mid.split_at_mut(index2).0
-            } else {
-                return Err(format!("Direction goes beyond slice [len = {}, destination = {}..{}]. ",
-                        mid.len(), destination, destination + chunksize));
-            };
+        if destination + chunksize > slice.len() {
+            return Err(MovesliceError::OutOfBounds {
+                dest_start: destination,
+                dest_end: destination + chunksize,
+                len: slice.len(),
+            });
+        }
 
-            mid.rotate_left(chunk.1-chunk.0);
-        } else if destination < chunk.0 {
-            let index1 = destination;
-            let index2 = chunk.1 - destination;
+        if destination > chunk.start {
+            // Overlapping forward copy: walk from the highest index down so a
+            // source element is never clobbered before it has been read.
+            for i in (0..chunksize).rev() {
+                slice[destination + i] = slice[chunk.start + i];
+            }
+        } else if destination < chunk.start {
+            for i in 0..chunksize {
+                slice[destination + i] = slice[chunk.start + i];
+            }
+        }
 
-            let (_, mid) = slice.split_at_mut(index1);
+        Ok(())
+}
 
-            let mid = mid.split_at_mut(index2).0;
+    fn swap_ranges(&mut self, a: R, b: R) -> Result<(), Self::Err>
+    where R: RangeBounds<usize>
+    {
+        let slice = self.as_mut();
+        let len = slice.len();
+        let a = resolve_range(a, len)?;
+        let b = resolve_range(b, len)?;
 
-            mid.rotate_right(chunk.1-chunk.0);
+        // The two ranges must be disjoint with `a` entirely before `b`.
+        if a.end > b.start {
+            return Err(MovesliceError::RangesOverlap { a, b });
         }
 
+        // Swap the two blocks in place by extending the three-reversal rotation
+        // trick to four reversals: reverse each block and the gap between
+        // them, then reverse the whole span [a.start..b.end].
+        slice[a.start..a.end].reverse();
+        slice[a.end..b.start].reverse();
+        slice[b.start..b.end].reverse();
+        slice[a.start..b.end].reverse();
+
         Ok(())
-}
+    }
 }
\ No newline at end of file